@@ -0,0 +1,257 @@
+//! Hardware-wallet signing flow (Ledger via WebHID).
+//!
+//! For a Ledger-derived Pallas key, the secret key must never enter WASM
+//! memory. This module splits transaction construction from signing:
+//! `build_*_signing_request` returns the transaction's packed roinput bytes
+//! (the same bytes the software signer in [`crate::transaction`] hashes)
+//! together with the network id, and `assemble_signed_*` takes the `rx || s`
+//! signature bytes the device returns and emits the same broadcastable JSON
+//! the software signer produces, by reusing its transaction-building and
+//! serialization code directly.
+//!
+//! The roinput bytes alone are NOT the literal bytes a hardware device
+//! hashes: Mina's Poseidon sponge absorbs the network's domain string into
+//! its initial state rather than treating it as a message prefix, and a
+//! Ledger Mina app applies that domain separation itself from the network id
+//! it's told. [`SigningRequest::network`] carries that id so the caller can
+//! pass it to the device out of band, alongside the payload bytes.
+
+use wasm_bindgen::prelude::*;
+
+use crate::transaction::{
+    build_transaction, graphql_payload_json, transaction_roinput_bytes, TransactionFields, TransactionKind,
+};
+use crate::MinaNetwork;
+
+/// An unsigned transaction payload ready to hand to an external (e.g.
+/// hardware) signer: the packed roinput bytes plus the network id the
+/// signer must use to domain-separate its hash.
+#[wasm_bindgen]
+pub struct SigningRequest {
+    payload_hex: String,
+    network: MinaNetwork,
+}
+
+#[wasm_bindgen]
+impl SigningRequest {
+    /// The transaction's packed roinput bytes, as hex.
+    #[wasm_bindgen(getter)]
+    pub fn payload_hex(&self) -> String {
+        self.payload_hex.clone()
+    }
+
+    /// The network the signer must domain-separate its hash for.
+    #[wasm_bindgen(getter)]
+    pub fn network(&self) -> MinaNetwork {
+        self.network
+    }
+}
+
+/// Build the unsigned signing request for a payment.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn build_payment_signing_request(
+    from: &str,
+    to: &str,
+    amount: u64,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    network: MinaNetwork,
+) -> Result<SigningRequest, JsError> {
+    let fields = TransactionFields { from, to, amount, fee, nonce, valid_until, memo };
+    let transaction = build_transaction(TransactionKind::Payment, &fields)?;
+    Ok(SigningRequest { payload_hex: hex::encode(transaction_roinput_bytes(&transaction)), network })
+}
+
+/// Build the unsigned signing request for a stake delegation.
+#[wasm_bindgen]
+pub fn build_delegation_signing_request(
+    from: &str,
+    to: &str,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    network: MinaNetwork,
+) -> Result<SigningRequest, JsError> {
+    let fields = TransactionFields { from, to, amount: 0, fee, nonce, valid_until, memo };
+    let transaction = build_transaction(TransactionKind::StakeDelegation, &fields)?;
+    Ok(SigningRequest { payload_hex: hex::encode(transaction_roinput_bytes(&transaction)), network })
+}
+
+/// Assemble a broadcastable `sendPayment` JSON payload from a signature produced externally
+/// (e.g. by a Ledger device), without ever needing the sender's secret key.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn assemble_signed_payment(
+    from: &str,
+    to: &str,
+    amount: u64,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    signature_hex: &str,
+) -> Result<String, JsError> {
+    let fields = TransactionFields { from, to, amount, fee, nonce, valid_until, memo };
+    graphql_payload_json(TransactionKind::Payment, &fields, signature_hex)
+}
+
+/// Assemble a broadcastable `sendDelegation` JSON payload from a signature produced externally
+/// (e.g. by a Ledger device), without ever needing the sender's secret key.
+#[wasm_bindgen]
+pub fn assemble_signed_delegation(
+    from: &str,
+    to: &str,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    signature_hex: &str,
+) -> Result<String, JsError> {
+    let fields = TransactionFields { from, to, amount: 0, fee, nonce, valid_until, memo };
+    graphql_payload_json(TransactionKind::StakeDelegation, &fields, signature_hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::signature_to_hex;
+    use crate::{generate_keypair, sign_payment, MinaNetwork};
+    use mina_signer::{Keypair, NetworkId, Signer};
+
+    #[test]
+    fn test_signing_request_matches_transaction_roinput_bytes_and_carries_network() {
+        let sender = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let request = build_payment_signing_request(
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Testnet,
+        )
+        .unwrap();
+
+        assert_eq!(request.network(), MinaNetwork::Testnet);
+
+        let fields = TransactionFields {
+            from: &sender.address(),
+            to: &receiver.address(),
+            amount: 1_000_000_000,
+            fee: 10_000_000,
+            nonce: 0,
+            valid_until: u32::MAX,
+            memo: "",
+        };
+        let transaction = build_transaction(TransactionKind::Payment, &fields).unwrap();
+        assert_eq!(request.payload_hex(), hex::encode(transaction_roinput_bytes(&transaction)));
+    }
+
+    #[test]
+    fn test_assemble_signed_payment_matches_software_signer() {
+        let sender = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let software_signed = sign_payment(
+            &sender.secret_key_hex(),
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        )
+        .unwrap();
+
+        // Simulate an external (hardware) signer producing the same signature.
+        let keypair = Keypair::from_hex(&sender.secret_key_hex()).unwrap();
+        let fields = TransactionFields {
+            from: &sender.address(),
+            to: &receiver.address(),
+            amount: 1_000_000_000,
+            fee: 10_000_000,
+            nonce: 0,
+            valid_until: u32::MAX,
+            memo: "",
+        };
+        let transaction = build_transaction(TransactionKind::Payment, &fields).unwrap();
+        let mut signer = mina_signer::create_kimchi(NetworkId::MAINNET);
+        let signature_hex = signature_to_hex(&signer.sign(&keypair, &transaction));
+
+        let assembled = assemble_signed_payment(
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            &signature_hex,
+        )
+        .unwrap();
+
+        assert_eq!(assembled, software_signed.graphql_payload_json());
+    }
+
+    #[test]
+    fn test_assemble_signed_payment_signature_is_decimal() {
+        let sender = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let software_signed = sign_payment(
+            &sender.secret_key_hex(),
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        )
+        .unwrap();
+
+        let assembled = assemble_signed_payment(
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            &software_signed.signature_hex(),
+        )
+        .unwrap();
+
+        let payload: serde_json::Value = serde_json::from_str(&assembled).unwrap();
+        let field = payload["signature"]["field"].as_str().unwrap();
+        assert!(field.chars().all(|c| c.is_ascii_digit()), "signature field must be decimal, not hex");
+    }
+
+    #[test]
+    fn test_assemble_signed_delegation_rejects_malformed_signature() {
+        let sender = generate_keypair().unwrap();
+        let delegate = generate_keypair().unwrap();
+
+        let result = assemble_signed_delegation(
+            &sender.address(),
+            &delegate.address(),
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            "not-a-signature",
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! Password-encrypted keystore JSON, so the browser extension never has to
+//! persist a raw secret key hex to disk.
+//!
+//! The format follows the Ethereum secret-storage model: scrypt stretches the
+//! password into a 32-byte derived key, the first 16 bytes of which are used
+//! as an AES-128-CTR key for the secret scalar, and the last 16 bytes are
+//! hashed together with the ciphertext into a MAC that lets `decrypt_keystore`
+//! detect a wrong password before it ever produces (wrong) key material.
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use mina_signer::{Keypair, SecKey};
+use rand::RngCore;
+use scrypt::Params as ScryptParams;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use wasm_bindgen::prelude::*;
+
+use crate::MinaKeypair;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const KEYSTORE_VERSION: u32 = 1;
+const SCRYPT_LOG_N: u8 = 14; // n = 2^14 = 16384
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const DERIVED_KEY_LEN: usize = 32;
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreJson {
+    crypto: CryptoParams,
+    address: String,
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CryptoParams {
+    cipher: String,
+    ciphertext: String,
+    cipherparams: CipherParams,
+    kdf: String,
+    kdfparams: ScryptKdfParams,
+    mac: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScryptKdfParams {
+    n: u32,
+    r: u32,
+    p: u32,
+    dklen: usize,
+    salt: String,
+}
+
+fn derive_key(password: &str, params: &ScryptKdfParams) -> Result<Vec<u8>, JsError> {
+    let salt = hex::decode(&params.salt).map_err(|e| JsError::new(&format!("Invalid salt: {e}")))?;
+    let log_n = (params.n as f64).log2().round() as u8;
+    let scrypt_params = ScryptParams::new(log_n, params.r, params.p, params.dklen)
+        .map_err(|e| JsError::new(&format!("Invalid scrypt params: {e}")))?;
+
+    let mut derived_key = vec![0u8; params.dklen];
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut derived_key)
+        .map_err(|e| JsError::new(&format!("Key derivation failed: {e}")))?;
+    Ok(derived_key)
+}
+
+fn compute_mac(derived_key: &[u8], ciphertext: &[u8]) -> String {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived_key[16..32]);
+    hasher.update(ciphertext);
+    hex::encode(hasher.finalize())
+}
+
+/// Encrypt a secret key hex into a versioned, password-protected keystore JSON blob.
+#[wasm_bindgen]
+pub fn encrypt_keystore(secret_key_hex: &str, password: &str) -> Result<String, JsError> {
+    let sec_key = SecKey::from_hex(secret_key_hex)
+        .map_err(|e| JsError::new(&format!("Invalid secret key: {:?}", e)))?;
+    let keypair = Keypair::from_secret_key(sec_key)
+        .map_err(|e| JsError::new(&format!("Keypair derivation failed: {:?}", e)))?;
+    let address = keypair.public.into_address();
+
+    let mut salt = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    let kdfparams = ScryptKdfParams {
+        n: 1u32 << SCRYPT_LOG_N,
+        r: SCRYPT_R,
+        p: SCRYPT_P,
+        dklen: DERIVED_KEY_LEN,
+        salt: hex::encode(salt),
+    };
+    let derived_key = derive_key(password, &kdfparams)?;
+
+    let mut iv = [0u8; 16];
+    rand::rngs::OsRng.fill_bytes(&mut iv);
+    let mut ciphertext = hex::decode(secret_key_hex)
+        .map_err(|e| JsError::new(&format!("Invalid secret key hex: {e}")))?;
+    let mut cipher = Aes128Ctr::new(derived_key[..16].into(), (&iv).into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mac = compute_mac(&derived_key, &ciphertext);
+
+    let keystore = KeystoreJson {
+        crypto: CryptoParams {
+            cipher: "aes-128-ctr".to_string(),
+            ciphertext: hex::encode(&ciphertext),
+            cipherparams: CipherParams { iv: hex::encode(iv) },
+            kdf: "scrypt".to_string(),
+            kdfparams,
+            mac,
+        },
+        address,
+        version: KEYSTORE_VERSION,
+    };
+
+    serde_json::to_string(&keystore).map_err(|e| JsError::new(&format!("Serialization failed: {e}")))
+}
+
+/// Recover a `MinaKeypair` from a keystore JSON blob, given the password it was encrypted with.
+///
+/// The MAC is recomputed and compared before decryption is attempted, so a
+/// wrong password fails with a clear error rather than yielding a bogus key.
+#[wasm_bindgen]
+pub fn decrypt_keystore(keystore_json: &str, password: &str) -> Result<MinaKeypair, JsError> {
+    let keystore: KeystoreJson =
+        serde_json::from_str(keystore_json).map_err(|e| JsError::new(&format!("Invalid keystore JSON: {e}")))?;
+
+    let derived_key = derive_key(password, &keystore.crypto.kdfparams)?;
+    let ciphertext = hex::decode(&keystore.crypto.ciphertext)
+        .map_err(|e| JsError::new(&format!("Invalid ciphertext: {e}")))?;
+
+    let expected_mac = compute_mac(&derived_key, &ciphertext);
+    if expected_mac != keystore.crypto.mac {
+        return Err(JsError::new("Incorrect password (MAC mismatch)"));
+    }
+
+    let iv = hex::decode(&keystore.crypto.cipherparams.iv)
+        .map_err(|e| JsError::new(&format!("Invalid IV: {e}")))?;
+    let mut secret_bytes = ciphertext;
+    let mut cipher = Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into());
+    cipher.apply_keystream(&mut secret_bytes);
+
+    let sec_key = SecKey::from_hex(&hex::encode(secret_bytes))
+        .map_err(|e| JsError::new(&format!("Decrypted secret key is invalid: {:?}", e)))?;
+    let keypair = Keypair::from_secret_key(sec_key)
+        .map_err(|e| JsError::new(&format!("Keypair derivation failed: {:?}", e)))?;
+
+    Ok(MinaKeypair::from_keypair(keypair))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_keypair;
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let keypair = generate_keypair().unwrap();
+        let keystore = encrypt_keystore(&keypair.secret_key_hex(), "correct horse battery staple").unwrap();
+        let recovered = decrypt_keystore(&keystore, "correct horse battery staple").unwrap();
+
+        assert_eq!(keypair.address(), recovered.address());
+        assert_eq!(keypair.secret_key_hex(), recovered.secret_key_hex());
+    }
+
+    #[test]
+    fn test_keystore_wrong_password_fails_with_clear_error() {
+        let keypair = generate_keypair().unwrap();
+        let keystore = encrypt_keystore(&keypair.secret_key_hex(), "correct horse battery staple").unwrap();
+
+        let err = decrypt_keystore(&keystore, "wrong password").unwrap_err();
+        assert!(format!("{:?}", err).contains("Incorrect password"));
+    }
+
+    #[test]
+    fn test_keystore_contains_expected_fields() {
+        let keypair = generate_keypair().unwrap();
+        let keystore = encrypt_keystore(&keypair.secret_key_hex(), "pw").unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+
+        assert_eq!(parsed["version"], 1);
+        assert_eq!(parsed["address"], keypair.address());
+        assert_eq!(parsed["crypto"]["cipher"], "aes-128-ctr");
+        assert_eq!(parsed["crypto"]["kdf"], "scrypt");
+    }
+
+    #[test]
+    fn test_keystore_never_contains_raw_secret_key() {
+        let keypair = generate_keypair().unwrap();
+        let keystore = encrypt_keystore(&keypair.secret_key_hex(), "pw").unwrap();
+
+        assert!(!keystore.contains(&keypair.secret_key_hex()));
+    }
+}
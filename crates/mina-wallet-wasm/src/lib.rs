@@ -6,11 +6,32 @@
 //! - Keypair generation using Mina's Pallas curve
 //! - Schnorr signature creation and verification
 //! - Address encoding/decoding (B62 format)
+//! - BIP39 mnemonics and hierarchical deterministic account derivation
+//! - Password-encrypted keystore JSON import/export
+//! - Payment and delegation transaction signing
+//! - Hardware-wallet (Ledger) signing flow via unsigned-payload export
+//! - Bulk and vanity-prefix address generation
 
 use mina_signer::{CompressedPubKey, Keypair, PubKey, SecKey};
 use rand::rngs::OsRng;
 use wasm_bindgen::prelude::*;
 
+mod hardware;
+mod keystore;
+mod mnemonic;
+mod signing;
+mod transaction;
+mod vanity;
+pub use hardware::{
+    assemble_signed_delegation, assemble_signed_payment, build_delegation_signing_request,
+    build_payment_signing_request, SigningRequest,
+};
+pub use keystore::{decrypt_keystore, encrypt_keystore};
+pub use mnemonic::{derive_keypair_from_mnemonic, generate_mnemonic, mnemonic_to_seed, validate_mnemonic};
+pub use signing::{sign_message, verify_signature, MinaNetwork};
+pub use transaction::{sign_delegation, sign_payment, SignedTransaction};
+pub use vanity::{generate_keypairs, generate_vanity_keypair};
+
 /// Result of keypair generation.
 #[wasm_bindgen]
 pub struct MinaKeypair {
@@ -22,6 +43,16 @@ pub struct MinaKeypair {
     secret_key_hex: String,
 }
 
+impl MinaKeypair {
+    /// Build a `MinaKeypair` from a `mina_signer` keypair.
+    pub(crate) fn from_keypair(keypair: Keypair) -> Self {
+        let address = keypair.public.into_address();
+        let public_key_hex = keypair.public.to_hex();
+        let secret_key_hex = keypair.secret.to_hex();
+        MinaKeypair { address, public_key_hex, secret_key_hex }
+    }
+}
+
 #[wasm_bindgen]
 impl MinaKeypair {
     /// Get the Mina address (B62...).
@@ -0,0 +1,191 @@
+//! BIP39 mnemonics and hierarchical deterministic account derivation.
+//!
+//! Mina wallets such as Auro and Clorio derive every account from a single
+//! BIP39 seed phrase along the path `m/44'/12586'/account'/0/0` (Mina's
+//! registered SLIP-44 coin type is `12586`). Derivation follows the
+//! SLIP-0010 Ed25519 scheme (fully hardened, since non-hardened derivation
+//! is undefined for Ed25519-style curves), and the 32-byte child key at the
+//! end of the path has its top two bits cleared, which puts it below the
+//! Pallas scalar field order, before it is converted to a scalar the same
+//! way `SecKey` converts any raw scalar, then turned into a `Keypair`.
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use mina_signer::{Keypair, ScalarField, SecKey};
+use rand::RngCore;
+use sha2::Sha512;
+use wasm_bindgen::prelude::*;
+
+use crate::MinaKeypair;
+
+/// Mina's coin type, per SLIP-44: https://github.com/satoshilabs/slips/blob/master/slip-0044.md
+const MINA_COIN_TYPE: u32 = 12586;
+
+/// Generate a new BIP39 mnemonic.
+///
+/// `word_count` must be one of 12, 15, 18, 21, or 24.
+#[wasm_bindgen]
+pub fn generate_mnemonic(word_count: u32) -> Result<String, JsError> {
+    let entropy_bytes = match word_count {
+        12 => 16,
+        15 => 20,
+        18 => 24,
+        21 => 28,
+        24 => 32,
+        _ => return Err(JsError::new("word_count must be one of 12, 15, 18, 21, 24")),
+    };
+
+    let mut entropy = vec![0u8; entropy_bytes];
+    rand::rngs::OsRng.fill_bytes(&mut entropy);
+
+    let mnemonic = Mnemonic::from_entropy(&entropy)
+        .map_err(|e| JsError::new(&format!("Mnemonic generation failed: {e}")))?;
+
+    Ok(mnemonic.to_string())
+}
+
+/// Validate a mnemonic's wordlist membership and checksum.
+#[wasm_bindgen]
+pub fn validate_mnemonic(mnemonic: &str) -> bool {
+    mnemonic.parse::<Mnemonic>().is_ok()
+}
+
+/// Derive the 64-byte BIP39 seed (as hex) from a mnemonic and optional passphrase.
+#[wasm_bindgen]
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<String, JsError> {
+    let mnemonic: Mnemonic =
+        mnemonic.parse().map_err(|e| JsError::new(&format!("Invalid mnemonic: {e}")))?;
+    Ok(hex::encode(mnemonic.to_seed(passphrase)))
+}
+
+/// Derive a Mina keypair from a mnemonic at `m/44'/12586'/account'/0'/0'`
+/// (every level hardened).
+#[wasm_bindgen]
+pub fn derive_keypair_from_mnemonic(mnemonic: &str, account_index: u32) -> Result<MinaKeypair, JsError> {
+    let mnemonic: Mnemonic =
+        mnemonic.parse().map_err(|e| JsError::new(&format!("Invalid mnemonic: {e}")))?;
+    let seed = mnemonic.to_seed("");
+
+    let path = [44, MINA_COIN_TYPE, account_index, 0, 0];
+    let (mut child_key, _chain_code) = derive_hardened_path(&seed, &path)?;
+
+    // Clamp the top two bits so the big-endian child node is already less than
+    // the Pallas scalar field order, so the mod-order conversion below never
+    // reduces it.
+    child_key[0] &= 0x3f;
+
+    let scalar = ScalarField::from_be_bytes_mod_order(&child_key);
+    let keypair = Keypair::from_secret_key(SecKey::new(scalar))
+        .map_err(|e| JsError::new(&format!("Keypair derivation failed: {:?}", e)))?;
+
+    Ok(MinaKeypair::from_keypair(keypair))
+}
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Walk a fully-hardened SLIP-0010 Ed25519-style derivation path, returning
+/// the final (key, chain_code) pair.
+fn derive_hardened_path(seed: &[u8], path: &[u32]) -> Result<([u8; 32], [u8; 32]), JsError> {
+    let mut mac = HmacSha512::new_from_slice(b"ed25519 seed")
+        .map_err(|e| JsError::new(&format!("HMAC init failed: {e}")))?;
+    mac.update(seed);
+    let i = mac.finalize().into_bytes();
+
+    let mut key: [u8; 32] = i[..32].try_into().unwrap();
+    let mut chain_code: [u8; 32] = i[32..].try_into().unwrap();
+
+    for &index in path {
+        let hardened_index = index | 0x8000_0000;
+
+        let mut mac = HmacSha512::new_from_slice(&chain_code)
+            .map_err(|e| JsError::new(&format!("HMAC init failed: {e}")))?;
+        mac.update(&[0u8]);
+        mac.update(&key);
+        mac.update(&hardened_index.to_be_bytes());
+        let i = mac.finalize().into_bytes();
+
+        key = i[..32].try_into().unwrap();
+        chain_code = i[32..].try_into().unwrap();
+    }
+
+    Ok((key, chain_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_mnemonic_word_counts() {
+        for (word_count, expected_words) in [(12, 12), (15, 15), (18, 18), (21, 21), (24, 24)] {
+            let mnemonic = generate_mnemonic(word_count).unwrap();
+            assert_eq!(mnemonic.split_whitespace().count(), expected_words);
+            assert!(validate_mnemonic(&mnemonic), "generated mnemonic should validate");
+        }
+    }
+
+    #[test]
+    fn test_generate_mnemonic_rejects_invalid_word_count() {
+        assert!(generate_mnemonic(13).is_err());
+    }
+
+    #[test]
+    fn test_validate_mnemonic_rejects_garbage() {
+        assert!(!validate_mnemonic("not a valid mnemonic phrase at all"));
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed_is_deterministic() {
+        let mnemonic = generate_mnemonic(12).unwrap();
+        let seed_a = mnemonic_to_seed(&mnemonic, "").unwrap();
+        let seed_b = mnemonic_to_seed(&mnemonic, "").unwrap();
+        assert_eq!(seed_a, seed_b);
+        assert_eq!(seed_a.len(), 128, "seed should be 64 bytes hex-encoded");
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed_depends_on_passphrase() {
+        let mnemonic = generate_mnemonic(12).unwrap();
+        let seed_a = mnemonic_to_seed(&mnemonic, "").unwrap();
+        let seed_b = mnemonic_to_seed(&mnemonic, "passphrase").unwrap();
+        assert_ne!(seed_a, seed_b);
+    }
+
+    #[test]
+    fn test_derive_keypair_from_mnemonic_deterministic() {
+        let mnemonic = generate_mnemonic(24).unwrap();
+        let keypair_a = derive_keypair_from_mnemonic(&mnemonic, 0).unwrap();
+        let keypair_b = derive_keypair_from_mnemonic(&mnemonic, 0).unwrap();
+        assert_eq!(keypair_a.address(), keypair_b.address());
+    }
+
+    #[test]
+    fn test_derive_keypair_from_mnemonic_varies_by_account_index() {
+        let mnemonic = generate_mnemonic(24).unwrap();
+        let account_0 = derive_keypair_from_mnemonic(&mnemonic, 0).unwrap();
+        let account_1 = derive_keypair_from_mnemonic(&mnemonic, 1).unwrap();
+        assert_ne!(account_0.address(), account_1.address());
+    }
+
+    #[test]
+    fn test_derive_hardened_path_child_key_is_clamped_before_use() {
+        // Regardless of what the raw SLIP-0010 child node looks like, the top
+        // two bits must be cleared before it's reduced into a scalar, so the
+        // scalar is the clamped child node itself rather than a value
+        // silently reduced mod the field order.
+        let mnemonic = generate_mnemonic(24).unwrap();
+        let seed = hex::decode(mnemonic_to_seed(&mnemonic, "").unwrap()).unwrap();
+        let path = [44, MINA_COIN_TYPE, 0, 0, 0];
+        let (child_key, _) = derive_hardened_path(&seed, &path).unwrap();
+
+        let clamped_scalar = ScalarField::from_be_bytes_mod_order(&{
+            let mut clamped = child_key;
+            clamped[0] &= 0x3f;
+            clamped
+        });
+        let keypair = Keypair::from_secret_key(SecKey::new(clamped_scalar)).unwrap();
+
+        let derived = derive_keypair_from_mnemonic(&mnemonic, 0).unwrap();
+        assert_eq!(derived.address(), keypair.public.into_address());
+    }
+}
@@ -0,0 +1,153 @@
+//! Schnorr message signing and verification.
+//!
+//! Mina uses a Schnorr signature scheme over the Pallas curve, domain-separated
+//! by network id so that a signature produced for testnet can never be replayed
+//! as a valid mainnet signature (and vice versa). This module signs/verifies
+//! arbitrary UTF-8 messages; see [`crate::transaction`] for signing the
+//! structured payment/delegation payloads Mina transactions actually use.
+
+use ark_ff::{BigInteger, PrimeField};
+use mina_signer::{BaseField, Hashable, Keypair, NetworkId, PubKey, ROInput, ScalarField, Signature, Signer};
+use wasm_bindgen::prelude::*;
+
+/// Network a signature is domain-separated for.
+///
+/// Mirrors `mina_signer::NetworkId`, exposed as its own type since `wasm_bindgen`
+/// cannot bind directly to a foreign crate's enum.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinaNetwork {
+    Mainnet,
+    Testnet,
+}
+
+impl From<MinaNetwork> for NetworkId {
+    fn from(network: MinaNetwork) -> Self {
+        match network {
+            MinaNetwork::Mainnet => NetworkId::MAINNET,
+            MinaNetwork::Testnet => NetworkId::TESTNET,
+        }
+    }
+}
+
+/// Wraps a UTF-8 message so it can be hashed and signed via `mina_signer`.
+struct SignableMessage(String);
+
+impl Hashable for SignableMessage {
+    type D = NetworkId;
+
+    fn to_roinput(&self) -> ROInput {
+        ROInput::new().append_bytes(self.0.as_bytes())
+    }
+
+    fn domain_string(network_id: NetworkId) -> Option<String> {
+        network_domain_string(network_id)
+    }
+}
+
+/// Domain string Mina hashes into every signature so that a signature made
+/// for one network can never be replayed as valid on another.
+pub(crate) fn network_domain_string(network_id: NetworkId) -> Option<String> {
+    match network_id {
+        NetworkId::MAINNET => Some("MinaSignatureMainnet".to_string()),
+        NetworkId::TESTNET => Some("CodaSignature".to_string()),
+    }
+}
+
+fn field_to_hex<F: PrimeField>(field: &F) -> String {
+    hex::encode(field.into_bigint().to_bytes_le())
+}
+
+/// Render a field element as the decimal big-integer string Mina's GraphQL
+/// `SignatureInput` expects (not hex, and not the field's internal
+/// Montgomery representation).
+pub(crate) fn field_to_decimal<F: PrimeField>(field: &F) -> String {
+    num_bigint::BigUint::from_bytes_le(&field.into_bigint().to_bytes_le()).to_string()
+}
+
+fn field_from_hex<F: PrimeField>(hex_str: &str) -> Result<F, JsError> {
+    let bytes = hex::decode(hex_str).map_err(|e| JsError::new(&format!("Invalid hex: {e}")))?;
+    Ok(F::from_le_bytes_mod_order(&bytes))
+}
+
+pub(crate) fn signature_to_hex(signature: &Signature) -> String {
+    format!("{}{}", field_to_hex(&signature.rx), field_to_hex(&signature.s))
+}
+
+pub(crate) fn signature_from_hex(signature_hex: &str) -> Result<Signature, JsError> {
+    if signature_hex.len() != 128 {
+        return Err(JsError::new("Signature hex must be 128 characters (rx || s, 32 bytes each)"));
+    }
+    let (rx_hex, s_hex) = signature_hex.split_at(64);
+    let rx: BaseField = field_from_hex(rx_hex)?;
+    let s: ScalarField = field_from_hex(s_hex)?;
+    Ok(Signature::new(rx, s))
+}
+
+/// Sign an arbitrary message with a Schnorr signature, domain-separated by `network`.
+///
+/// Returns the signature as `rx || s` hex (32-byte field element, 32-byte scalar).
+#[wasm_bindgen]
+pub fn sign_message(secret_key_hex: &str, message: &str, network: MinaNetwork) -> Result<String, JsError> {
+    let keypair = Keypair::from_hex(secret_key_hex)
+        .map_err(|e| JsError::new(&format!("Invalid secret key: {:?}", e)))?;
+
+    let mut signer = mina_signer::create_kimchi(NetworkId::from(network));
+    let signature = signer.sign(&keypair, &SignableMessage(message.to_string()));
+
+    Ok(signature_to_hex(&signature))
+}
+
+/// Verify a Schnorr signature produced by [`sign_message`].
+#[wasm_bindgen]
+pub fn verify_signature(
+    public_key_hex: &str,
+    message: &str,
+    signature_hex: &str,
+    network: MinaNetwork,
+) -> Result<bool, JsError> {
+    let pub_key = PubKey::from_hex(public_key_hex)
+        .map_err(|e| JsError::new(&format!("Invalid public key: {:?}", e)))?;
+    let signature = signature_from_hex(signature_hex)?;
+
+    let mut signer = mina_signer::create_kimchi(NetworkId::from(network));
+    Ok(signer.verify(&signature, &pub_key, &SignableMessage(message.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_keypair;
+
+    #[test]
+    fn test_sign_and_verify_roundtrip() {
+        let keypair = generate_keypair().unwrap();
+        let signature = sign_message(&keypair.secret_key_hex(), "hello mina", MinaNetwork::Mainnet).unwrap();
+
+        assert!(verify_signature(&keypair.public_key_hex(), "hello mina", &signature, MinaNetwork::Mainnet)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_message() {
+        let keypair = generate_keypair().unwrap();
+        let signature = sign_message(&keypair.secret_key_hex(), "hello mina", MinaNetwork::Mainnet).unwrap();
+
+        assert!(!verify_signature(&keypair.public_key_hex(), "goodbye mina", &signature, MinaNetwork::Mainnet)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_network() {
+        let keypair = generate_keypair().unwrap();
+        let signature = sign_message(&keypair.secret_key_hex(), "hello mina", MinaNetwork::Mainnet).unwrap();
+
+        assert!(!verify_signature(&keypair.public_key_hex(), "hello mina", &signature, MinaNetwork::Testnet)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_signature_from_hex_rejects_wrong_length() {
+        assert!(signature_from_hex("deadbeef").is_err());
+    }
+}
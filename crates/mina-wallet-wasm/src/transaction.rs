@@ -0,0 +1,454 @@
+//! Mina payment and delegation transaction signing.
+//!
+//! Builds the signable roinput for a Mina user command (the fee payer,
+//! source/receiver, nonce, fee, memo and tag fields Mina's protocol packs
+//! into a transaction) and produces a detached Schnorr signature over it,
+//! plus a JSON payload shaped for the `sendPayment` / `sendDelegation`
+//! Mina GraphQL mutations.
+//!
+//! The transaction-building logic in this module (`TransactionFields`,
+//! [`build_transaction`], [`transaction_roinput_bytes`], [`graphql_payload_json`])
+//! is also reused by [`crate::hardware`], so that the hardware-wallet flow and
+//! the in-crate software signer always encode a transaction identically.
+
+use mina_signer::{CompressedPubKey, Hashable, Keypair, NetworkId, ROInput, Signer};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::signing::{field_to_decimal, network_domain_string, signature_from_hex, signature_to_hex};
+use crate::MinaNetwork;
+
+/// Default Mina token id (the native MINA token).
+const MINA_TOKEN_ID: u64 = 1;
+/// Maximum memo length in bytes; Mina memos are fixed 34-byte fields.
+const MEMO_MAX_LEN: usize = 32;
+
+/// Which Mina user command a [`Transaction`] represents.
+#[derive(Clone, Copy)]
+pub(crate) enum TransactionKind {
+    Payment,
+    StakeDelegation,
+}
+
+impl TransactionKind {
+    /// Tag bits packed into the roinput, per Mina's `Transaction_union_tag` encoding.
+    fn tag_bits(self) -> [bool; 3] {
+        match self {
+            TransactionKind::Payment => [false, false, false],
+            TransactionKind::StakeDelegation => [false, false, true],
+        }
+    }
+}
+
+/// The caller-supplied fields of a single Mina user command, before they're
+/// packed into a signable [`Transaction`]. `amount` is ignored for delegations.
+pub(crate) struct TransactionFields<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub amount: u64,
+    pub fee: u64,
+    pub nonce: u32,
+    pub valid_until: u32,
+    pub memo: &'a str,
+}
+
+/// The fields of a single Mina user command (payment or stake delegation),
+/// packed into a roinput the same way the protocol does for signing.
+struct Transaction {
+    fee: u64,
+    fee_token: u64,
+    fee_payer_pk: CompressedPubKey,
+    nonce: u32,
+    valid_until: u32,
+    memo: [u8; 34],
+    kind: TransactionKind,
+    source_pk: CompressedPubKey,
+    receiver_pk: CompressedPubKey,
+    token_id: u64,
+    amount: u64,
+    /// Whether the receiving account's token permissions get locked. Mina
+    /// wallets never set this for ordinary payments/delegations, but the
+    /// protocol's roinput layout still packs the bit, so it's represented
+    /// explicitly rather than silently omitted.
+    token_locked: bool,
+}
+
+impl Hashable for Transaction {
+    type D = NetworkId;
+
+    fn to_roinput(&self) -> ROInput {
+        let tag = self.kind.tag_bits();
+        ROInput::new()
+            .append_u64(self.fee)
+            .append_u64(self.fee_token)
+            .append_field(self.fee_payer_pk.x)
+            .append_bit(self.fee_payer_pk.is_odd)
+            .append_u32(self.nonce)
+            .append_u32(self.valid_until)
+            .append_bytes(&self.memo)
+            .append_bit(tag[0])
+            .append_bit(tag[1])
+            .append_bit(tag[2])
+            .append_field(self.source_pk.x)
+            .append_bit(self.source_pk.is_odd)
+            .append_field(self.receiver_pk.x)
+            .append_bit(self.receiver_pk.is_odd)
+            .append_u64(self.token_id)
+            .append_u64(self.amount)
+            .append_bit(self.token_locked)
+    }
+
+    fn domain_string(network_id: NetworkId) -> Option<String> {
+        network_domain_string(network_id)
+    }
+}
+
+fn encode_memo(memo: &str) -> Result<[u8; 34], JsError> {
+    let bytes = memo.as_bytes();
+    if bytes.len() > MEMO_MAX_LEN {
+        return Err(JsError::new(&format!("Memo must be at most {MEMO_MAX_LEN} bytes")));
+    }
+
+    let mut encoded = [0u8; 34];
+    encoded[0] = 0x01; // digest tag: payload is the literal message, not a hash of it
+    encoded[1] = bytes.len() as u8;
+    encoded[2..2 + bytes.len()].copy_from_slice(bytes);
+    Ok(encoded)
+}
+
+fn parse_pub_key(address: &str, field_name: &str) -> Result<CompressedPubKey, JsError> {
+    CompressedPubKey::from_address(address)
+        .map_err(|e| JsError::new(&format!("Invalid {field_name} address: {:?}", e)))
+}
+
+/// Pack [`TransactionFields`] into the signable [`Transaction`] layout for `kind`.
+pub(crate) fn build_transaction(kind: TransactionKind, fields: &TransactionFields) -> Result<Transaction, JsError> {
+    let fee_payer_pk = parse_pub_key(fields.from, "sender")?;
+    let receiver_field_name = match kind {
+        TransactionKind::Payment => "receiver",
+        TransactionKind::StakeDelegation => "delegate",
+    };
+    let receiver_pk = parse_pub_key(fields.to, receiver_field_name)?;
+    let amount = match kind {
+        TransactionKind::Payment => fields.amount,
+        TransactionKind::StakeDelegation => 0,
+    };
+
+    Ok(Transaction {
+        fee: fields.fee,
+        fee_token: MINA_TOKEN_ID,
+        fee_payer_pk: fee_payer_pk.clone(),
+        nonce: fields.nonce,
+        valid_until: fields.valid_until,
+        memo: encode_memo(fields.memo)?,
+        kind,
+        source_pk: fee_payer_pk,
+        receiver_pk,
+        token_id: MINA_TOKEN_ID,
+        amount,
+        token_locked: false,
+    })
+}
+
+/// The transaction's packed roinput bytes, in this crate's internal
+/// serialization — the same bytes the software signer in this module hashes
+/// via `mina_signer::Signer::sign`.
+///
+/// This is NOT the literal byte string a hardware device hashes: Mina's
+/// Poseidon sponge absorbs the network's domain string into its initial
+/// state rather than treating it as a prefix of the message, and a Ledger
+/// Mina app applies that domain separation itself from the network id it's
+/// told, not from anything concatenated into this payload. Callers driving
+/// an external/hardware signer must pass the network id to it out of band
+/// (see [`crate::hardware::SigningRequest`]) alongside these bytes.
+pub(crate) fn transaction_roinput_bytes(transaction: &Transaction) -> Vec<u8> {
+    transaction.to_roinput().to_bytes()
+}
+
+/// A signed, ready-to-broadcast Mina transaction.
+#[wasm_bindgen]
+pub struct SignedTransaction {
+    graphql_payload_json: String,
+    signature_hex: String,
+}
+
+#[wasm_bindgen]
+impl SignedTransaction {
+    /// JSON payload matching the variables shape of Mina's `sendPayment` /
+    /// `sendDelegation` GraphQL mutations.
+    #[wasm_bindgen(getter)]
+    pub fn graphql_payload_json(&self) -> String {
+        self.graphql_payload_json.clone()
+    }
+
+    /// The detached Schnorr signature, as `rx || s` hex.
+    #[wasm_bindgen(getter)]
+    pub fn signature_hex(&self) -> String {
+        self.signature_hex.clone()
+    }
+}
+
+#[derive(Serialize)]
+struct SignatureFields {
+    field: String,
+    scalar: String,
+}
+
+#[derive(Serialize)]
+struct PaymentInput {
+    from: String,
+    to: String,
+    amount: String,
+    fee: String,
+    nonce: String,
+    memo: String,
+    #[serde(rename = "validUntil")]
+    valid_until: String,
+}
+
+#[derive(Serialize)]
+struct DelegationInput {
+    from: String,
+    to: String,
+    fee: String,
+    nonce: String,
+    memo: String,
+    #[serde(rename = "validUntil")]
+    valid_until: String,
+}
+
+#[derive(Serialize)]
+struct GraphqlPayload<I: Serialize> {
+    input: I,
+    signature: SignatureFields,
+}
+
+/// Build the `sendPayment` / `sendDelegation`-shaped JSON payload for `fields`, given a signature.
+///
+/// Used both by the software signer in this module and by
+/// [`crate::hardware::assemble_signed_payment`] / [`crate::hardware::assemble_signed_delegation`],
+/// which supply a signature produced off-device instead.
+pub(crate) fn graphql_payload_json(
+    kind: TransactionKind,
+    fields: &TransactionFields,
+    signature_hex: &str,
+) -> Result<String, JsError> {
+    let parsed_signature = signature_from_hex(signature_hex)?;
+    // Mina's GraphQL `SignatureInput` takes each field element as a decimal
+    // big-integer string, not hex: the `rx || s` hex is this crate's wire
+    // format, not the node's.
+    let signature = SignatureFields {
+        field: field_to_decimal(&parsed_signature.rx),
+        scalar: field_to_decimal(&parsed_signature.s),
+    };
+
+    let json = match kind {
+        TransactionKind::Payment => serde_json::to_string(&GraphqlPayload {
+            input: PaymentInput {
+                from: fields.from.to_string(),
+                to: fields.to.to_string(),
+                amount: fields.amount.to_string(),
+                fee: fields.fee.to_string(),
+                nonce: fields.nonce.to_string(),
+                memo: fields.memo.to_string(),
+                valid_until: fields.valid_until.to_string(),
+            },
+            signature,
+        }),
+        TransactionKind::StakeDelegation => serde_json::to_string(&GraphqlPayload {
+            input: DelegationInput {
+                from: fields.from.to_string(),
+                to: fields.to.to_string(),
+                fee: fields.fee.to_string(),
+                nonce: fields.nonce.to_string(),
+                memo: fields.memo.to_string(),
+                valid_until: fields.valid_until.to_string(),
+            },
+            signature,
+        }),
+    };
+
+    json.map_err(|e| JsError::new(&format!("Serialization failed: {e}")))
+}
+
+fn sign_transaction(
+    sender_secret_key_hex: &str,
+    from: &str,
+    transaction: &Transaction,
+    network: MinaNetwork,
+) -> Result<String, JsError> {
+    let keypair = Keypair::from_hex(sender_secret_key_hex)
+        .map_err(|e| JsError::new(&format!("Invalid secret key: {:?}", e)))?;
+    if keypair.public.into_address() != from {
+        return Err(JsError::new("Secret key does not match the fee payer address"));
+    }
+
+    let mut signer = mina_signer::create_kimchi(NetworkId::from(network));
+    let signature = signer.sign(&keypair, transaction);
+    Ok(signature_to_hex(&signature))
+}
+
+/// Sign a Mina payment, returning the detached signature and a `sendPayment`-shaped JSON payload.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn sign_payment(
+    sender_secret_key_hex: &str,
+    from: &str,
+    to: &str,
+    amount: u64,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    network: MinaNetwork,
+) -> Result<SignedTransaction, JsError> {
+    let fields = TransactionFields { from, to, amount, fee, nonce, valid_until, memo };
+    let transaction = build_transaction(TransactionKind::Payment, &fields)?;
+
+    let signature_hex = sign_transaction(sender_secret_key_hex, from, &transaction, network)?;
+    let graphql_payload_json = graphql_payload_json(TransactionKind::Payment, &fields, &signature_hex)?;
+
+    Ok(SignedTransaction { graphql_payload_json, signature_hex })
+}
+
+/// Sign a Mina stake delegation, returning the detached signature and a `sendDelegation`-shaped JSON payload.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen]
+pub fn sign_delegation(
+    sender_secret_key_hex: &str,
+    from: &str,
+    to: &str,
+    fee: u64,
+    nonce: u32,
+    valid_until: u32,
+    memo: &str,
+    network: MinaNetwork,
+) -> Result<SignedTransaction, JsError> {
+    let fields = TransactionFields { from, to, amount: 0, fee, nonce, valid_until, memo };
+    let transaction = build_transaction(TransactionKind::StakeDelegation, &fields)?;
+
+    let signature_hex = sign_transaction(sender_secret_key_hex, from, &transaction, network)?;
+    let graphql_payload_json = graphql_payload_json(TransactionKind::StakeDelegation, &fields, &signature_hex)?;
+
+    Ok(SignedTransaction { graphql_payload_json, signature_hex })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_keypair;
+
+    #[test]
+    fn test_sign_payment_signature_verifies() {
+        let sender = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let signed = sign_payment(
+            &sender.secret_key_hex(),
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        )
+        .unwrap();
+
+        let fields = TransactionFields {
+            from: &sender.address(),
+            to: &receiver.address(),
+            amount: 1_000_000_000,
+            fee: 10_000_000,
+            nonce: 0,
+            valid_until: u32::MAX,
+            memo: "",
+        };
+        let transaction = build_transaction(TransactionKind::Payment, &fields).unwrap();
+
+        let pub_key = mina_signer::PubKey::from_address(&sender.address()).unwrap();
+        let signature = crate::signing::signature_from_hex(&signed.signature_hex()).unwrap();
+        let mut signer = mina_signer::create_kimchi(NetworkId::from(MinaNetwork::Mainnet));
+        assert!(signer.verify(&signature, &pub_key, &transaction));
+    }
+
+    #[test]
+    fn test_sign_payment_rejects_mismatched_sender() {
+        let sender = generate_keypair().unwrap();
+        let other = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let result = sign_payment(
+            &sender.secret_key_hex(),
+            &other.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sign_delegation_payload_has_no_amount() {
+        let sender = generate_keypair().unwrap();
+        let delegate = generate_keypair().unwrap();
+
+        let signed = sign_delegation(
+            &sender.secret_key_hex(),
+            &sender.address(),
+            &delegate.address(),
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        )
+        .unwrap();
+
+        let payload: serde_json::Value = serde_json::from_str(&signed.graphql_payload_json()).unwrap();
+        assert!(payload["input"].get("amount").is_none());
+    }
+
+    #[test]
+    fn test_encode_memo_rejects_too_long() {
+        assert!(encode_memo(&"a".repeat(MEMO_MAX_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_graphql_payload_signature_is_decimal_not_hex() {
+        let sender = generate_keypair().unwrap();
+        let receiver = generate_keypair().unwrap();
+
+        let signed = sign_payment(
+            &sender.secret_key_hex(),
+            &sender.address(),
+            &receiver.address(),
+            1_000_000_000,
+            10_000_000,
+            0,
+            u32::MAX,
+            "",
+            MinaNetwork::Mainnet,
+        )
+        .unwrap();
+
+        let payload: serde_json::Value = serde_json::from_str(&signed.graphql_payload_json()).unwrap();
+        let field = payload["signature"]["field"].as_str().unwrap();
+        let scalar = payload["signature"]["scalar"].as_str().unwrap();
+
+        // A Mina GraphQL `SignatureInput` takes decimal big-integer strings:
+        // every character must be an ASCII digit, not a hex nibble.
+        assert!(!field.is_empty() && field.chars().all(|c| c.is_ascii_digit()));
+        assert!(!scalar.is_empty() && scalar.chars().all(|c| c.is_ascii_digit()));
+
+        let signature = crate::signing::signature_from_hex(&signed.signature_hex()).unwrap();
+        assert_eq!(field, crate::signing::field_to_decimal(&signature.rx));
+        assert_eq!(scalar, crate::signing::field_to_decimal(&signature.s));
+    }
+}
@@ -0,0 +1,126 @@
+//! Bulk keypair generation and vanity address search.
+//!
+//! Every Mina address is a base58check encoding of a fixed version byte plus
+//! the compressed public key, so every address starts with the same
+//! [`FIXED_ADDRESS_PREFIX`] regardless of the key — only the characters after
+//! it vary from key to key. `generate_vanity_keypair` rejects a requested
+//! prefix up front if it can never be achieved, rather than spinning forever.
+
+use mina_signer::Keypair;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+use crate::MinaKeypair;
+
+/// The leading characters common to every Mina address, fixed by the address
+/// format's version byte.
+const FIXED_ADDRESS_PREFIX: &str = "B62q";
+
+/// Base58 (Bitcoin alphabet) excludes `0`, `O`, `I`, `l`, since they're easily
+/// confused in print; no Mina address can ever contain them.
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn validate_prefix(prefix: &str) -> Result<(), JsError> {
+    // Byte-slicing at a fixed index would panic on a prefix containing a
+    // multibyte char that straddles the boundary, so compare whole strings
+    // instead: the prefix is reachable as long as one is a prefix of the other.
+    if !FIXED_ADDRESS_PREFIX.starts_with(prefix) && !prefix.starts_with(FIXED_ADDRESS_PREFIX) {
+        return Err(JsError::new(&format!(
+            "Unreachable prefix: every Mina address starts with \"{FIXED_ADDRESS_PREFIX}\""
+        )));
+    }
+
+    if let Some(bad_char) = prefix.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        return Err(JsError::new(&format!(
+            "Unreachable prefix: \"{bad_char}\" is not a base58 character, so no address can contain it"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Search for a keypair whose address starts with `prefix`, giving up after `max_attempts` draws.
+#[wasm_bindgen]
+pub fn generate_vanity_keypair(prefix: &str, max_attempts: u32) -> Result<MinaKeypair, JsError> {
+    validate_prefix(prefix)?;
+
+    let mut rng = OsRng;
+    for _ in 0..max_attempts {
+        let keypair = Keypair::rand(&mut rng)
+            .map_err(|e| JsError::new(&format!("Keypair generation failed: {:?}", e)))?;
+        if keypair.public.into_address().starts_with(prefix) {
+            return Ok(MinaKeypair::from_keypair(keypair));
+        }
+    }
+
+    Err(JsError::new(&format!("No address matching \"{prefix}\" found within {max_attempts} attempts")))
+}
+
+/// Generate `count` independent keypairs.
+#[wasm_bindgen]
+pub fn generate_keypairs(count: u32) -> Result<Vec<MinaKeypair>, JsError> {
+    let mut rng = OsRng;
+    (0..count)
+        .map(|_| {
+            Keypair::rand(&mut rng)
+                .map(MinaKeypair::from_keypair)
+                .map_err(|e| JsError::new(&format!("Keypair generation failed: {:?}", e)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_vanity_keypair_matches_prefix() {
+        let keypair = generate_vanity_keypair("B62q", 10_000).unwrap();
+        assert!(keypair.address().starts_with("B62q"));
+    }
+
+    #[test]
+    fn test_generate_vanity_keypair_rejects_unreachable_prefix() {
+        assert!(generate_vanity_keypair("XYZ", 10).is_err());
+    }
+
+    #[test]
+    fn test_generate_vanity_keypair_rejects_multibyte_prefix_without_panicking() {
+        // "€" spans bytes 3-5, straddling the fixed prefix's 4-byte boundary.
+        assert!(generate_vanity_keypair("B62€", 10).is_err());
+    }
+
+    #[test]
+    fn test_generate_vanity_keypair_rejects_non_base58_characters() {
+        // Each of these would pass the fixed-prefix `starts_with` check yet can
+        // never appear in a real address, so callers must not be left spinning
+        // to `max_attempts` trying to match them.
+        for prefix in ["B62q0", "B62qO", "B62qI", "B62ql"] {
+            assert!(generate_vanity_keypair(prefix, 10).is_err(), "{prefix} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_generate_vanity_keypair_reports_exhausted_budget() {
+        // A 6-character suffix is astronomically unlikely within a handful of attempts.
+        let result = generate_vanity_keypair("B62qZZZZZZ", 5);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_keypairs_returns_unique_addresses() {
+        use std::collections::HashSet;
+
+        let keypairs = generate_keypairs(10).unwrap();
+        assert_eq!(keypairs.len(), 10);
+
+        let addresses: HashSet<String> = keypairs.iter().map(|k| k.address()).collect();
+        assert_eq!(addresses.len(), 10, "all generated addresses should be unique");
+    }
+
+    #[test]
+    fn test_generate_keypairs_zero_returns_empty() {
+        let keypairs = generate_keypairs(0).unwrap();
+        assert!(keypairs.is_empty());
+    }
+}